@@ -6,16 +6,22 @@ use std::str::FromStr;
 
 use apca::api::v1::account;
 use apca::api::v1::asset;
+use apca::api::v1::events;
 use apca::api::v1::order;
 use apca::api::v1::orders;
+use apca::api::v1::position;
+use apca::api::v1::positions;
 use apca::ApiInfo;
 use apca::Client;
 
 use futures::future::Future;
 use futures::future::ok;
+use futures::Stream;
 
 use num_decimal::Num;
 
+use serde_json::json;
+
 use simplelog::Config;
 use simplelog::LevelFilter;
 use simplelog::SimpleLogger;
@@ -36,6 +42,34 @@ struct Opts {
   /// Increase verbosity (can be supplied multiple times).
   #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
   verbosity: usize,
+  /// The output format to use.
+  #[structopt(short = "o", long = "output", default_value = "text")]
+  output: OutputFormat,
+}
+
+
+/// The output format used for rendering command results.
+#[derive(Debug, Clone, Copy)]
+enum OutputFormat {
+  /// Print aligned, human-readable tables.
+  Text,
+  /// Print machine-readable JSON.
+  Json,
+}
+
+impl FromStr for OutputFormat {
+  type Err = String;
+
+  fn from_str(format: &str) -> Result<Self, Self::Err> {
+    match format {
+      "text" => Ok(OutputFormat::Text),
+      "json" => Ok(OutputFormat::Json),
+      s => Err(format!(
+        "{} is not a valid output format (use 'text' or 'json')",
+        s
+      )),
+    }
+  }
 }
 
 /// A command line client for automated trading with Alpaca.
@@ -47,6 +81,45 @@ enum Command {
   /// Perform various order related functions.
   #[structopt(name = "order")]
   Order(Order),
+  /// List open positions along with their unrealized profit or loss.
+  #[structopt(name = "positions")]
+  Positions,
+  /// Stream live order and trade updates over websocket.
+  #[structopt(name = "watch")]
+  Watch {
+    /// Only show updates for the given symbol.
+    #[structopt(long = "symbol")]
+    symbol: Option<String>,
+  },
+  /// Buy an asset (a market order, unless --at or --stop is given).
+  #[structopt(name = "buy")]
+  Buy {
+    #[structopt(flatten)]
+    args: BuySellArgs,
+  },
+  /// Sell an asset (a market order, unless --at or --stop is given).
+  #[structopt(name = "sell")]
+  Sell {
+    #[structopt(flatten)]
+    args: BuySellArgs,
+  },
+}
+
+
+/// The arguments shared by the 'buy'/'sell' convenience subcommands
+/// and their equivalents under 'order'.
+#[derive(Debug, StructOpt)]
+struct BuySellArgs {
+  /// The symbol of the asset to trade.
+  symbol: String,
+  /// The quantity to trade.
+  quantity: u64,
+  /// Submit a limit order with the given limit price instead of a market order.
+  #[structopt(long = "at")]
+  at: Option<Num>,
+  /// Submit a stop order with the given stop price instead of a market order.
+  #[structopt(long = "stop")]
+  stop: Option<Num>,
 }
 
 
@@ -67,7 +140,39 @@ enum Order {
     /// Create a stop order (or stop limit order) with the given stop price.
     #[structopt(short = "s", long = "stop")]
     stop_price: Option<Num>,
+    /// Create a trailing-stop order trailing the high-water (or
+    /// low-water) mark by the given percentage.
+    ///
+    /// The Alpaca API client we talk to does not implement trailing-stop
+    /// orders yet, so supplying this flag currently results in an error.
+    #[structopt(
+      long = "trail-percent",
+      conflicts_with = "limit_price",
+      conflicts_with = "stop_price",
+      conflicts_with = "trail_price"
+    )]
+    trail_percent: Option<Num>,
+    /// Create a trailing-stop order trailing the high-water (or
+    /// low-water) mark by the given absolute price delta.
+    ///
+    /// The Alpaca API client we talk to does not implement trailing-stop
+    /// orders yet, so supplying this flag currently results in an error.
+    #[structopt(
+      long = "trail-price",
+      conflicts_with = "limit_price",
+      conflicts_with = "stop_price"
+    )]
+    trail_price: Option<Num>,
+    /// Only activate the order once the market crosses the given
+    /// price (requires --trail-percent or --trail-price).
+    #[structopt(long = "activation")]
+    activation_price: Option<Num>,
+    /// The time in force policy to use for the order.
+    #[structopt(long = "tif", conflicts_with = "today")]
+    time_in_force: Option<TimeInForce>,
     /// Create an order that is only valid for today.
+    ///
+    /// This flag is deprecated; use `--tif day` instead.
     #[structopt(long = "today")]
     today: bool,
   },
@@ -76,7 +181,53 @@ enum Order {
   Cancel { id: OrderId },
   /// List orders.
   #[structopt(name = "list")]
-  List,
+  List {
+    /// Only list orders with the given status.
+    #[structopt(long = "status", default_value = "open")]
+    status: OrderStatusFilter,
+    /// Only list orders for the given symbol.
+    #[structopt(long = "symbol")]
+    symbol: Option<String>,
+  },
+  /// Buy an asset (a market order, unless --at or --stop is given).
+  #[structopt(name = "buy")]
+  Buy {
+    #[structopt(flatten)]
+    args: BuySellArgs,
+  },
+  /// Sell an asset (a market order, unless --at or --stop is given).
+  #[structopt(name = "sell")]
+  Sell {
+    #[structopt(flatten)]
+    args: BuySellArgs,
+  },
+}
+
+
+#[derive(Debug)]
+enum OrderStatusFilter {
+  /// Only orders that are still open.
+  Open,
+  /// Only orders that are no longer open.
+  Closed,
+  /// Both open and closed orders.
+  All,
+}
+
+impl FromStr for OrderStatusFilter {
+  type Err = String;
+
+  fn from_str(status: &str) -> Result<Self, Self::Err> {
+    match status {
+      "open" => Ok(OrderStatusFilter::Open),
+      "closed" => Ok(OrderStatusFilter::Closed),
+      "all" => Ok(OrderStatusFilter::All),
+      s => Err(format!(
+        "{} is not a valid status specification (use 'open', 'closed', or 'all')",
+        s
+      )),
+    }
+  }
 }
 
 
@@ -104,6 +255,51 @@ impl FromStr for Side {
 }
 
 
+#[derive(Debug)]
+enum TimeInForce {
+  /// The order is valid for today only.
+  Day,
+  /// The order is valid until it is canceled.
+  UntilCanceled,
+  /// The order has to be filled immediately, in full or in part;
+  /// any unfilled remainder is canceled right away.
+  ///
+  /// The Alpaca API client we talk to does not implement this policy
+  /// yet, so selecting it currently results in an error.
+  ImmediateOrCancel,
+  /// The order has to be filled in its entirety immediately or it is
+  /// canceled in full.
+  ///
+  /// The Alpaca API client we talk to does not implement this policy
+  /// yet, so selecting it currently results in an error.
+  FillOrKill,
+  /// The order is only eligible for execution as part of the
+  /// opening auction.
+  ///
+  /// The Alpaca API client we talk to does not implement this policy
+  /// yet, so selecting it currently results in an error.
+  AtTheOpen,
+}
+
+impl FromStr for TimeInForce {
+  type Err = String;
+
+  fn from_str(tif: &str) -> Result<Self, Self::Err> {
+    match tif {
+      "day" => Ok(TimeInForce::Day),
+      "gtc" => Ok(TimeInForce::UntilCanceled),
+      "ioc" => Ok(TimeInForce::ImmediateOrCancel),
+      "fok" => Ok(TimeInForce::FillOrKill),
+      "opg" => Ok(TimeInForce::AtTheOpen),
+      s => Err(format!(
+        "{} is not a valid time in force specification (use 'day', 'gtc', 'ioc', 'fok', or 'opg')",
+        s
+      )),
+    }
+  }
+}
+
+
 #[derive(Debug)]
 struct OrderId(order::Id);
 
@@ -131,13 +327,34 @@ fn format_account_status(status: account::Status) -> String {
 
 
 /// The handler for the 'account' command.
-fn account(client: Client) -> Result<Box<dyn Future<Item = (), Error = String>>, String> {
+fn account(
+  client: Client,
+  output: OutputFormat,
+) -> Result<Box<dyn Future<Item = (), Error = String>>, String> {
   let fut = client
     .issue::<account::Get>(())
     .map_err(|e| format!("failed to issue GET request to account endpoint: {}", e))?
     .map_err(|e| format!("failed to retrieve account information: {}", e))
-    .and_then(|account| {
-      println!(r#"account:
+    .and_then(move |account| {
+      match output {
+        OutputFormat::Json => {
+          let json = json!({
+            "id": account.id.to_hyphenated_ref().to_string(),
+            "status": format_account_status(account.status),
+            "currency": account.currency,
+            "buying_power": account.buying_power.to_string(),
+            "cash": account.cash.to_string(),
+            "withdrawable_cash": account.withdrawable_cash.to_string(),
+            "portfolio_value": account.portfolio_value.to_string(),
+            "day_trader": account.day_trader,
+            "trading_blocked": account.trading_blocked,
+            "transfers_blocked": account.transfers_blocked,
+            "account_blocked": account.account_blocked,
+          });
+          println!("{}", json);
+        },
+        OutputFormat::Text => {
+          println!(r#"account:
   id:                {id}
   status:            {status}
   buying power:      {buying_power} {currency}
@@ -148,18 +365,20 @@ fn account(client: Client) -> Result<Box<dyn Future<Item = (), Error = String>>,
   trading blocked:   {trading_blocked}
   transfers blocked: {transfers_blocked}
   account blocked:   {account_blocked}"#,
-        id = account.id.to_hyphenated_ref(),
-        status = format_account_status(account.status),
-        currency = account.currency,
-        buying_power = account.buying_power,
-        cash = account.cash,
-        withdrawable_cash = account.withdrawable_cash,
-        portfolio_value = account.portfolio_value,
-        day_trader = account.day_trader,
-        trading_blocked = account.trading_blocked,
-        transfers_blocked = account.transfers_blocked,
-        account_blocked = account.account_blocked,
-      );
+            id = account.id.to_hyphenated_ref(),
+            status = format_account_status(account.status),
+            currency = account.currency,
+            buying_power = account.buying_power,
+            cash = account.cash,
+            withdrawable_cash = account.withdrawable_cash,
+            portfolio_value = account.portfolio_value,
+            day_trader = account.day_trader,
+            trading_blocked = account.trading_blocked,
+            transfers_blocked = account.transfers_blocked,
+            account_blocked = account.account_blocked,
+          );
+        },
+      }
       ok(())
     });
 
@@ -171,6 +390,7 @@ fn account(client: Client) -> Result<Box<dyn Future<Item = (), Error = String>>,
 fn order(
   client: Client,
   order: Order,
+  output: OutputFormat,
 ) -> Result<Box<dyn Future<Item = (), Error = String>>, String> {
   match order {
     Order::Submit {
@@ -179,6 +399,10 @@ fn order(
       quantity,
       limit_price,
       stop_price,
+      trail_percent,
+      trail_price,
+      activation_price,
+      time_in_force,
       today,
     } => {
       let side = match side {
@@ -186,6 +410,25 @@ fn order(
         Side::Sell => order::Side::Sell,
       };
 
+      let trailing = trail_percent.is_some() || trail_price.is_some();
+      if trailing && (limit_price.is_some() || stop_price.is_some()) {
+        return Err(
+          "--trail-percent/--trail-price cannot be combined with --limit or --stop".to_string(),
+        )
+      }
+      if activation_price.is_some() && !trailing {
+        return Err(
+          "--activation requires --trail-percent or --trail-price".to_string(),
+        )
+      }
+      if trailing {
+        return Err(
+          "trailing-stop orders are not supported by the Alpaca API client this \
+           program is built against; submit a limit or stop order instead"
+            .to_string(),
+        )
+      }
+
       let type_ = match (limit_price.is_some(), stop_price.is_some()) {
         (true, true) => order::Type::StopLimit,
         (true, false) => order::Type::Limit,
@@ -193,10 +436,20 @@ fn order(
         (false, false) => order::Type::Market,
       };
 
-      let time_in_force = if today {
-        order::TimeInForce::Day
-      } else {
-        order::TimeInForce::UntilCanceled
+      let time_in_force = match time_in_force {
+        Some(TimeInForce::Day) => order::TimeInForce::Day,
+        Some(TimeInForce::UntilCanceled) => order::TimeInForce::UntilCanceled,
+        Some(tif @ TimeInForce::ImmediateOrCancel)
+        | Some(tif @ TimeInForce::FillOrKill)
+        | Some(tif @ TimeInForce::AtTheOpen) => {
+          return Err(format!(
+            "--tif {:?} is not supported by the Alpaca API client this program is \
+             built against; use 'day' or 'gtc' instead",
+            tif,
+          ))
+        },
+        None if today => order::TimeInForce::Day,
+        None => order::TimeInForce::UntilCanceled,
       };
 
       let request = order::OrderReq {
@@ -211,16 +464,7 @@ fn order(
         stop_price,
       };
 
-      let fut = client
-        .issue::<order::Post>(request)
-        .map_err(|e| format!("failed to issue POST request to order endpoint: {}", e))?
-        .map_err(|e| format!("failed to submit order: {}", e))
-        .and_then(|order| {
-          println!("{}", order.id.to_hyphenated_ref());
-          ok(())
-        });
-
-      Ok(Box::new(fut))
+      submit_order(client, output, request)
     },
     Order::Cancel { id } => {
       let fut = client
@@ -229,11 +473,59 @@ fn order(
         .map_err(|e| format!("failed to cancel order: {}", e));
       Ok(Box::new(fut))
     },
-    Order::List => order_list(client),
+    Order::List { status, symbol } => order_list(client, output, status, symbol),
+    Order::Buy { args } => submit_order(client, output, simple_order_req(order::Side::Buy, args)),
+    Order::Sell { args } => submit_order(client, output, simple_order_req(order::Side::Sell, args)),
   }
 }
 
 
+/// Build a plain market/limit/stop order request for the 'buy'/'sell'
+/// convenience commands.
+fn simple_order_req(side: order::Side, args: BuySellArgs) -> order::OrderReq {
+  let type_ = match (args.at.is_some(), args.stop.is_some()) {
+    (true, true) => order::Type::StopLimit,
+    (true, false) => order::Type::Limit,
+    (false, true) => order::Type::Stop,
+    (false, false) => order::Type::Market,
+  };
+
+  order::OrderReq {
+    // TODO: We should probably support other forms of specifying
+    //       the symbol.
+    symbol: asset::Symbol::Sym(args.symbol),
+    quantity: args.quantity,
+    side,
+    type_,
+    time_in_force: order::TimeInForce::UntilCanceled,
+    limit_price: args.at,
+    stop_price: args.stop,
+  }
+}
+
+
+/// Submit an order request and print the resulting order id.
+fn submit_order(
+  client: Client,
+  output: OutputFormat,
+  request: order::OrderReq,
+) -> Result<Box<dyn Future<Item = (), Error = String>>, String> {
+  let fut = client
+    .issue::<order::Post>(request)
+    .map_err(|e| format!("failed to issue POST request to order endpoint: {}", e))?
+    .map_err(|e| format!("failed to submit order: {}", e))
+    .and_then(move |order| {
+      match output {
+        OutputFormat::Json => println!("{}", json!({"id": order.id.to_hyphenated_ref().to_string()})),
+        OutputFormat::Text => println!("{}", order.id.to_hyphenated_ref()),
+      }
+      ok(())
+    });
+
+  Ok(Box::new(fut))
+}
+
+
 /// Determine the maximum width of values produced by applying a
 /// function on each element of a slice.
 fn max_width<T, F>(slice: &[T], f: F) -> usize
@@ -250,26 +542,101 @@ fn format_quantity(quantity: &Num) -> String {
 }
 
 
+/// Format the filled quantity of an order relative to its total
+/// quantity.
+///
+/// The apca version we depend on does not expose an average fill
+/// price on `Order`, so we can only report progress, not price.
+fn format_fill(order: &order::Order) -> String {
+  format!(
+    "{filled}/{qty} filled",
+    filled = format_quantity(&order.filled_quantity),
+    qty = format_quantity(&order.quantity),
+  )
+}
+
+
+/// Check whether an order's status represents one that is still open,
+/// i.e., one that may still receive further updates.
+fn is_open_status(status: order::Status) -> bool {
+  match status {
+    order::Status::New
+    | order::Status::PartiallyFilled
+    | order::Status::Accepted
+    | order::Status::PendingNew
+    | order::Status::AcceptedForBidding
+    | order::Status::PendingCancel
+    | order::Status::Stopped => true,
+    order::Status::Filled
+    | order::Status::DoneForDay
+    | order::Status::Canceled
+    | order::Status::Expired
+    | order::Status::Rejected
+    | order::Status::Suspended
+    | order::Status::Calculated => false,
+  }
+}
+
+
 /// List all currently open orders.
-fn order_list(client: Client) -> Result<Box<dyn Future<Item = (), Error = String>>, String> {
+fn order_list(
+  client: Client,
+  output: OutputFormat,
+  status: OrderStatusFilter,
+  symbol: Option<String>,
+) -> Result<Box<dyn Future<Item = (), Error = String>>, String> {
   let account = client
     .issue::<account::Get>(())
     .map_err(|e| format!("failed to issue GET request to account endpoint: {}", e))?
     .map_err(|e| format!("failed to retrieve account information: {}", e));
 
+  // The apca version we depend on only lets us request a `limit` on
+  // the orders endpoint, so status and symbol filtering both happen
+  // client-side below.
   let request = orders::OrdersReq { limit: 500 };
   let orders = client
     .issue::<orders::Get>(request)
     .map_err(|e| format!("failed to issue GET request to orders endpoint: {}", e))?
     .map_err(|e| format!("failed to list orders: {}", e));
 
-  let fut = account.join(orders).and_then(|(account, mut orders)| {
+  let fut = account.join(orders).and_then(move |(account, mut orders)| {
     let currency = account.currency;
 
+    match status {
+      OrderStatusFilter::Open => orders.retain(|order| is_open_status(order.status)),
+      OrderStatusFilter::Closed => orders.retain(|order| !is_open_status(order.status)),
+      OrderStatusFilter::All => {},
+    }
+
+    if let Some(symbol) = &symbol {
+      orders.retain(|order| &order.symbol == symbol);
+    }
+
     orders.sort_by(|a, b| a.symbol.cmp(&b.symbol));
 
+    if let OutputFormat::Json = output {
+      let json: Vec<_> = orders.iter().map(|order| json!({
+        "id": order.id.to_hyphenated_ref().to_string(),
+        "symbol": order.symbol,
+        "side": match order.side {
+          order::Side::Buy => "buy",
+          order::Side::Sell => "sell",
+        },
+        "type": format!("{:?}", order.type_),
+        "status": format!("{:?}", order.status),
+        "time_in_force": format!("{:?}", order.time_in_force),
+        "quantity": order.quantity.to_string(),
+        "filled_quantity": order.filled_quantity.to_string(),
+        "limit_price": order.limit_price.as_ref().map(Num::to_string),
+        "stop_price": order.stop_price.as_ref().map(Num::to_string),
+      })).collect();
+      println!("{}", serde_json::Value::Array(json));
+      return ok(())
+    }
+
     let qty_max = max_width(&orders, |p| format_quantity(&p.quantity).len());
     let sym_max = max_width(&orders, |p| p.symbol.len());
+    let fill_max = max_width(&orders, |p| format_fill(p).len());
 
     for order in orders {
       let side = match order.side {
@@ -296,13 +663,15 @@ fn order_list(client: Client) -> Result<Box<dyn Future<Item = (), Error = String
       };
 
       println!(
-        "{id} {side:>4} {qty:>qty_width$} {sym:<sym_width$} {price}",
+        "{id} {side:>4} {qty:>qty_width$} {sym:<sym_width$} {fill:<fill_width$} {price}",
         id = order.id.to_hyphenated_ref(),
         side = side,
         qty_width = qty_max,
         qty = format!("{:.0}", order.quantity),
         sym_width = sym_max,
         sym = order.symbol,
+        fill_width = fill_max,
+        fill = format_fill(&order),
         price = price,
       )
     }
@@ -313,6 +682,162 @@ fn order_list(client: Client) -> Result<Box<dyn Future<Item = (), Error = String
 }
 
 
+/// List all currently open positions along with their unrealized P&L.
+fn positions(
+  client: Client,
+  output: OutputFormat,
+) -> Result<Box<dyn Future<Item = (), Error = String>>, String> {
+  let account = client
+    .issue::<account::Get>(())
+    .map_err(|e| format!("failed to issue GET request to account endpoint: {}", e))?
+    .map_err(|e| format!("failed to retrieve account information: {}", e));
+
+  let positions = client
+    .issue::<positions::Get>(())
+    .map_err(|e| format!("failed to issue GET request to positions endpoint: {}", e))?
+    .map_err(|e| format!("failed to list positions: {}", e));
+
+  let fut = account.join(positions).and_then(move |(account, mut positions)| {
+    let currency = account.currency;
+
+    positions.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+
+    if let OutputFormat::Json = output {
+      let json: Vec<_> = positions.iter().map(|position| json!({
+        "symbol": position.symbol,
+        "quantity": position.quantity.to_string(),
+        "average_entry_price": position.average_entry_price.to_string(),
+        "current_price": position.current_price.to_string(),
+        "market_value": position.market_value.to_string(),
+        "unrealized_gain_total": position.unrealized_gain_total.to_string(),
+        "unrealized_gain_total_percent": position.unrealized_gain_total_percent.to_string(),
+      })).collect();
+      println!("{}", serde_json::Value::Array(json));
+      return ok(())
+    }
+
+    let qty_max = max_width(&positions, |p| format_quantity(&p.quantity).len());
+    let sym_max = max_width(&positions, |p| p.symbol.len());
+
+    for position in positions {
+      // `position::Side` only has a `Long` variant in the apca version
+      // we depend on, so the server-computed gain figures already
+      // reflect the right sign without us having to track it here.
+      let pnl = position.unrealized_gain_total.clone();
+      let pnl_pct = position.unrealized_gain_total_percent.clone() * Num::from_int(100);
+
+      println!(
+        "{sym:<sym_width$} {qty:>qty_width$} entry @ {entry} {currency} current @ {current} {currency} value: {value} {currency} P&L: {pnl:+} {currency} ({pnl_pct:+.2}%)",
+        sym_width = sym_max,
+        sym = position.symbol,
+        qty_width = qty_max,
+        qty = format_quantity(&position.quantity),
+        entry = position.average_entry_price,
+        current = position.current_price,
+        currency = currency,
+        value = position.market_value,
+        pnl = pnl,
+        pnl_pct = pnl_pct,
+      )
+    }
+    ok(())
+  });
+
+  Ok(Box::new(fut))
+}
+
+
+/// Format a trade update event.
+fn format_event(event: &events::TradeStatus) -> &'static str {
+  match event {
+    events::TradeStatus::New => "new",
+    events::TradeStatus::PartialFill => "partial_fill",
+    events::TradeStatus::Filled => "fill",
+    events::TradeStatus::DoneForDay => "done_for_day",
+    events::TradeStatus::Canceled => "canceled",
+    events::TradeStatus::Expired => "expired",
+    events::TradeStatus::PendingCancel => "pending_cancel",
+    events::TradeStatus::Stopped => "stopped",
+    events::TradeStatus::Rejected => "rejected",
+    events::TradeStatus::Suspended => "suspended",
+    events::TradeStatus::PendingNew => "pending_new",
+    events::TradeStatus::Calculated => "calculated",
+  }
+}
+
+
+/// The column width used to render an order's quantity in `watch`.
+///
+/// Unlike `order_list`/`positions`, the stream is unbounded, so we
+/// cannot derive this from `max_width` over the full result set and
+/// instead fall back to a fixed width.
+const WATCH_QTY_WIDTH: usize = 8;
+
+
+/// Subscribe to the trade updates stream and print each order state
+/// transition as it arrives.
+fn watch(
+  client: Client,
+  output: OutputFormat,
+  symbol: Option<String>,
+) -> Result<Box<dyn Future<Item = (), Error = String>>, String> {
+  let fut = client
+    .subscribe::<events::TradeUpdates>()
+    .map_err(|e| format!("failed to subscribe to trade updates stream: {}", e))
+    .and_then(move |stream| {
+      stream
+        .map_err(|e| format!("error receiving trade update: {}", e))
+        .and_then(|update| update.map_err(|e| format!("failed to decode trade update: {}", e)))
+        .for_each(move |update| {
+          if let Some(symbol) = &symbol {
+            if &update.order.symbol != symbol {
+              return ok(())
+            }
+          }
+
+          match output {
+            OutputFormat::Json => {
+              let json = json!({
+                "event": format_event(&update.event),
+                "order": {
+                  "id": update.order.id.to_hyphenated_ref().to_string(),
+                  "symbol": update.order.symbol,
+                  "side": match update.order.side {
+                    order::Side::Buy => "buy",
+                    order::Side::Sell => "sell",
+                  },
+                  "quantity": update.order.quantity.to_string(),
+                  "filled_quantity": update.order.filled_quantity.to_string(),
+                },
+              });
+              println!("{}", json);
+            },
+            OutputFormat::Text => {
+              let side = match update.order.side {
+                order::Side::Buy => "buy",
+                order::Side::Sell => "sell",
+              };
+
+              println!(
+                "{id} {event:<12} {side:>4} {qty:>qty_width$} {sym} {fill}",
+                id = update.order.id.to_hyphenated_ref(),
+                event = format_event(&update.event),
+                side = side,
+                qty_width = WATCH_QTY_WIDTH,
+                qty = format_quantity(&update.order.quantity),
+                sym = update.order.symbol,
+                fill = format_fill(&update.order),
+              );
+            },
+          }
+          ok(())
+        })
+    });
+
+  Ok(Box::new(fut))
+}
+
+
 fn main() -> Result<(), String> {
   let opts = Opts::from_args();
   let level = match opts.verbosity {
@@ -330,9 +855,14 @@ fn main() -> Result<(), String> {
     format!("failed to create Alpaca client: {}", e)
   })?;
 
+  let output = opts.output;
   let future = match opts.command {
-    Command::Account => account(client),
-    Command::Order(order) => self::order(client, order),
+    Command::Account => account(client, output),
+    Command::Order(order) => self::order(client, order, output),
+    Command::Positions => positions(client, output),
+    Command::Watch { symbol } => watch(client, output, symbol),
+    Command::Buy { args } => self::order(client, Order::Buy { args }, output),
+    Command::Sell { args } => self::order(client, Order::Sell { args }, output),
   }?;
 
   block_on_all(future)